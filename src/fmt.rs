@@ -73,22 +73,58 @@ impl Unit {
         }
     }
 
-    fn format(&self, mut fmt: &mut fmt::Formatter, bytes: u64, style: &Style) -> fmt::Result {
+    /// The number of bytes that make up a single one of this unit, used to compute the scaled
+    /// value directly when a non-default [`Precision`] is requested.
+    const fn divisor(&self) -> f64 {
+        use self::Unit::*;
+
+        (match &self {
+            &Byte => 1,
+
+            &Kilobyte => KILOBYTE,
+            &Megabyte => MEGABYTE,
+            &Gigabyte => GIGABYTE,
+            &Terabyte => TERABYTE,
+            &Petabyte => PETABYTE,
+            &Exabyte => EXABYTE,
+
+            &Kibibyte => KIBIBYTE,
+            &Mebibyte => MEBIBYTE,
+            &Gibibyte => GIBIBYTE,
+            &Tebibyte => TEBIBYTE,
+            &Pebibyte => PEBIBYTE,
+            &Exbibyte => EXBIBYTE,
+        }) as f64
+    }
+
+    /// The abbreviated unit text (the fourth element of [`Self::text()`]), adjusted for SI
+    /// casing: when `si_casing` is set, the kilobyte unit is rendered as `"kB"` (lowercase `k`)
+    /// rather than `"KB"`, matching `df` and other SI-aware tooling. All other units are
+    /// unaffected, since their SI prefixes are already uppercase.
+    fn abbreviated_text(&self, si_casing: bool) -> &'static str {
+        if si_casing && matches!(self, Unit::Kilobyte) {
+            "kB"
+        } else {
+            self.text().3
+        }
+    }
+
+    fn format(&self, mut fmt: &mut fmt::Formatter, bytes: u64, style: &Style, si_casing: bool, sep: &str) -> fmt::Result {
         match (&style, bytes) {
             (&Style::Default, _) => match &self {
-                &Unit::Byte => self.format(&mut fmt, bytes, &Style::FullLowercase),
-                _ => self.format(&mut fmt, bytes, &Style::Abbreviated),
+                &Unit::Byte => self.format(&mut fmt, bytes, &Style::FullLowercase, si_casing, sep),
+                _ => self.format(&mut fmt, bytes, &Style::Abbreviated, si_casing, sep),
             },
 
-            (&Style::FullLowercase, 1) => write!(fmt, " {}", self.text().0),
-            (&Style::Full, 1) => write!(fmt, " {}", self.text().1),
-            (&Style::AbbreviatedLowercase, 1) => write!(fmt, " {}", self.text().2),
-            (&Style::Abbreviated, 1) => write!(fmt, " {}", self.text().3),
+            (&Style::FullLowercase, 1) => write!(fmt, "{}{}", sep, self.text().0),
+            (&Style::Full, 1) => write!(fmt, "{}{}", sep, self.text().1),
+            (&Style::AbbreviatedLowercase, 1) => write!(fmt, "{}{}", sep, self.text().2),
+            (&Style::Abbreviated, 1) => write!(fmt, "{}{}", sep, self.abbreviated_text(si_casing)),
 
-            (&Style::FullLowercase, _) => write!(fmt, " {}s", self.text().0),
-            (&Style::Full, _) => write!(fmt, " {}s", self.text().1),
-            (&Style::AbbreviatedLowercase, _) => write!(fmt, " {}", self.text().2),
-            (&Style::Abbreviated, _) => write!(fmt, " {}", self.text().3),
+            (&Style::FullLowercase, _) => write!(fmt, "{}{}s", sep, self.text().0),
+            (&Style::Full, _) => write!(fmt, "{}{}s", sep, self.text().1),
+            (&Style::AbbreviatedLowercase, _) => write!(fmt, "{}{}", sep, self.text().2),
+            (&Style::Abbreviated, _) => write!(fmt, "{}{}", sep, self.abbreviated_text(si_casing)),
         }
     }
 }
@@ -111,6 +147,23 @@ pub enum Style {
     FullLowercase,
 }
 
+/// An enumeration of supported precision controls to be used when formatting a [`Size`],
+/// specifying how many digits follow (or make up) the formatted number.
+#[non_exhaustive]
+#[derive(Copy, Clone, Debug)]
+pub enum Precision {
+    /// The default behavior: the decimal precision is chosen per magnitude bucket, e.g. `"1.29
+    /// GiB"` but `"12.9 GiB"` but `"129 GiB"`.
+    Default,
+    /// Always format with exactly this many digits after the decimal point, regardless of
+    /// magnitude, e.g. `FixedDecimals(3)` formats as `"1.290 GiB"`.
+    FixedDecimals(usize),
+    /// Round to this many significant figures, regardless of magnitude, e.g.
+    /// `SignificantDigits(3)` formats `1.2923 GiB` as `"1.29 GiB"` and `12.923 GiB` as `"12.9
+    /// GiB"`.
+    SignificantDigits(usize),
+}
+
 // Backwards-compatibility associated constants to mimic `Style` variants to enable compilation of
 // older code. They are all hidden from the docs.
 impl Style {
@@ -150,6 +203,9 @@ pub struct SizeFormatter<'a> {
     size: &'a Size,
     base: Base,
     style: Style,
+    precision: Precision,
+    si_casing: bool,
+    separator: &'static str,
 }
 
 impl<'a> SizeFormatter<'a> {
@@ -175,6 +231,38 @@ impl<'a> SizeFormatter<'a> {
         }
     }
 
+    /// Specify how many digits are used when formatting the scaled number, overriding the
+    /// default per-magnitude bucketing.
+    ///
+    /// See [`Precision`] for more information.
+    pub fn with_precision(self, precision: Precision) -> Self {
+        Self {
+            precision,
+            .. self
+        }
+    }
+
+    /// Specify whether to use SI-compliant casing for the kilobyte unit's abbreviation (`"kB"`
+    /// with a lowercase `k`, rather than `"KB"`) when using [`Style::Abbreviated`]. This matches
+    /// the casing used by `df` and other SI-aware tooling; all other base-10 abbreviations
+    /// (`MB`, `GB`, ...) are already uppercase and unaffected by this setting.
+    pub fn with_si_casing(self, si_casing: bool) -> Self {
+        Self {
+            si_casing,
+            .. self
+        }
+    }
+
+    /// Specify the separator written between the formatted number and its unit, replacing the
+    /// default `" "`. Pass `""` for compact output like `"1.29GiB"`, useful for tight terminal
+    /// tables or filenames.
+    pub fn with_separator(self, sep: &'static str) -> Self {
+        Self {
+            separator: sep,
+            .. self
+        }
+    }
+
     /// Returns the formatted `Size` as a `String`, formatted according to the current state of the
     /// `SizeFormatter` instance as modified via [`with_style()`](Self::with_style),
     /// [`with_base()`](Self::with_base), and co.
@@ -212,8 +300,17 @@ impl<'a> std::fmt::Display for SizeFormatter<'a> {
             }
         };
 
-        (rule.formatter)(&mut fmt, bytes)?;
-        rule.unit.format(&mut fmt, bytes, &self.style)?;
+        match self.precision {
+            Precision::Default => (rule.formatter)(&mut fmt, bytes)?,
+            Precision::FixedDecimals(decimals) => {
+                write!(fmt, "{:.*}", decimals, bytes as f64 / rule.unit.divisor())?
+            }
+            Precision::SignificantDigits(digits) => {
+                let scaled = bytes as f64 / rule.unit.divisor();
+                write!(fmt, "{}", format_significant_digits(scaled, digits))?
+            }
+        }
+        rule.unit.format(&mut fmt, bytes, &self.style, self.si_casing, self.separator)?;
 
         return Ok(());
     }
@@ -234,6 +331,9 @@ impl Size {
             size: &self,
             base: DEFAULT_BASE,
             style: DEFAULT_STYLE,
+            precision: Precision::Default,
+            si_casing: false,
+            separator: " ",
         }
     }
 }
@@ -244,6 +344,31 @@ struct FormatRule {
     unit: Unit,
 }
 
+/// Rounds `value` to `digits` significant figures and formats the result.
+///
+/// This actually rounds the value (rather than just picking a decimal-place count from its
+/// pre-rounding magnitude) so that a carry across a power of ten — e.g. `9.999` to 3 significant
+/// figures becoming `10.0`, not `10.00` — and a `digits` smaller than the value's integer-digit
+/// count — e.g. `999.0` to 1 significant figure becoming `1000`, not `999` — both come out right.
+fn format_significant_digits(value: f64, digits: usize) -> String {
+    if value == 0.0 || digits == 0 {
+        return format!("{:.0}", value);
+    }
+
+    let magnitude = value.abs().log10().floor();
+    let factor = 10f64.powf(digits as f64 - 1.0 - magnitude);
+    let rounded = (value * factor).round() / factor;
+
+    let rounded_magnitude = if rounded == 0.0 {
+        magnitude
+    } else {
+        rounded.abs().log10().floor()
+    };
+    let decimals = (digits as f64 - 1.0 - rounded_magnitude).max(0.0) as usize;
+
+    format!("{:.*}", decimals, rounded)
+}
+
 const BASE10_RULES: [FormatRule; 17] = [
     FormatRule {
         less_than: 1 * KILOBYTE as u64,
@@ -326,8 +451,12 @@ const BASE10_RULES: [FormatRule; 17] = [
         unit: Unit::Petabyte,
     },
     FormatRule {
+        // `bytes` is always within `i64`'s range (~9.2 EB), well under `10 * EXABYTE`, so a
+        // single bounded-precision bucket covers every representable value here; there's no room
+        // for the `.1`/`.0` sub-buckets the lower magnitudes use before rolling over to the next
+        // unit, since there is no unit above exabyte.
         less_than: u64::max_value(),
-        formatter: |fmt, bytes| write!(fmt, "{:0}", bytes as f64 / ((1i64 * EXABYTE) as f64)),
+        formatter: |fmt, bytes| write!(fmt, "{:.2}", bytes as f64 / ((1i64 * EXABYTE) as f64)),
         unit: Unit::Exabyte,
     },
 ];
@@ -414,8 +543,86 @@ const BASE2_RULES: [FormatRule; 17] = [
         unit: Unit::Pebibyte,
     },
     FormatRule {
+        // `bytes` is always within `i64`'s range (~8 EiB), well under `10 * EXBIBYTE`, so a
+        // single bounded-precision bucket covers every representable value here; there's no room
+        // for the `.1`/`.0` sub-buckets the lower magnitudes use before rolling over to the next
+        // unit, since there is no unit above exbibyte.
         less_than: u64::max_value(),
-        formatter: |fmt, bytes| write!(fmt, "{:0}", bytes as f64 / ((1i64 * EXBIBYTE) as f64)),
+        formatter: |fmt, bytes| write!(fmt, "{:.2}", bytes as f64 / ((1i64 * EXBIBYTE) as f64)),
         unit: Unit::Exbibyte,
     },
 ];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn significant_digits_rounds_carries_into_a_new_magnitude() {
+        assert_eq!(format_significant_digits(9.999, 3), "10.0");
+        assert_eq!(format_significant_digits(999.6, 3), "1000");
+    }
+
+    #[test]
+    fn significant_digits_rounds_down_to_fewer_digits_than_the_integer_part() {
+        assert_eq!(format_significant_digits(999.0, 1), "1000");
+    }
+
+    #[test]
+    fn significant_digits_matches_the_ordinary_case() {
+        assert_eq!(format_significant_digits(1.2923, 3), "1.29");
+        assert_eq!(format_significant_digits(12.923, 3), "12.9");
+    }
+
+    #[test]
+    fn with_precision_fixed_decimals_overrides_the_default_bucketing() {
+        let size = Size::Bytes(1_390_000_000);
+        let formatted = size.format().with_base(Base::Base2).with_precision(Precision::FixedDecimals(3)).to_string();
+        assert_eq!(formatted, "1.295 GiB");
+    }
+
+    #[test]
+    fn with_precision_significant_digits_overrides_the_default_bucketing() {
+        let size = Size::Bytes(1_074_000_000);
+        let formatted = size.format().with_base(Base::Base2).with_precision(Precision::SignificantDigits(3)).to_string();
+        assert_eq!(formatted, "1.00 GiB");
+    }
+
+    #[test]
+    fn with_si_casing_lowercases_only_the_kilobyte_prefix() {
+        let kb = Size::Bytes(1_500);
+        assert_eq!(
+            kb.format().with_base(Base::Base10).with_style(Style::Abbreviated).with_si_casing(true).to_string(),
+            "1.50 kB"
+        );
+
+        let mb = Size::Bytes(1_500_000);
+        assert_eq!(
+            mb.format().with_base(Base::Base10).with_style(Style::Abbreviated).with_si_casing(true).to_string(),
+            "1.50 MB"
+        );
+    }
+
+    #[test]
+    fn with_si_casing_false_keeps_the_default_uppercase_kb() {
+        let kb = Size::Bytes(1_500);
+        assert_eq!(
+            kb.format().with_base(Base::Base10).with_style(Style::Abbreviated).to_string(),
+            "1.50 KB"
+        );
+    }
+
+    #[test]
+    fn with_separator_overrides_the_default_space() {
+        let size = Size::Bytes(1_390_000_000);
+        assert_eq!(size.format().with_base(Base::Base2).with_separator("").to_string(), "1.29GiB");
+        assert_eq!(size.format().with_base(Base::Base2).with_separator("_").to_string(), "1.29_GiB");
+    }
+
+    #[test]
+    fn exabyte_and_exbibyte_buckets_use_bounded_precision() {
+        let size = Size::Bytes(i64::max_value());
+        assert_eq!(size.format().with_base(Base::Base10).to_string(), "9.22 EB");
+        assert_eq!(size.format().with_base(Base::Base2).to_string(), "8.00 EiB");
+    }
+}
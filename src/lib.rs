@@ -0,0 +1,159 @@
+//! `prettysize` provides [`Size`], a small wrapper around a byte count that can be constructed
+//! from a particular unit (kilobyte, mebibyte, ...), formatted as human-readable text via
+//! [`SizeFormatter`], and parsed back from such text via [`FromStr`](core::str::FromStr), which
+//! returns a [`ParseSizeError`] on failure.
+
+// `fmt` predates several clippy lints that have since become warn-by-default and conflict with
+// its established idiom: the `FormatRule` tables deliberately spell out `1 * UNIT` alongside
+// `10 * UNIT` and `100 * UNIT` so the magnitude progression reads consistently down the column,
+// it matches on `&self` by reference rather than binding by value, it uses explicit `return`s,
+// and `Size`/`SizeFormatter` intentionally shadow `Display::to_string` with an inherent method
+// to avoid an extra `&` at call sites (`size.to_string()` vs `(&size).to_string()`). The
+// backwards-compatibility `Style` aliases also predate clippy's semver check on `#[deprecated]`.
+#![allow(
+    clippy::identity_op,
+    clippy::legacy_numeric_constants,
+    clippy::match_ref_pats,
+    clippy::needless_borrow,
+    clippy::needless_return,
+    clippy::inherent_to_string_shadow_display,
+    clippy::deprecated_semver,
+)]
+
+use core::ops::{Add, Sub};
+
+mod fmt;
+mod parse;
+#[cfg(feature = "serde")]
+mod serde;
+
+#[cfg(test)]
+mod tests_nostd;
+
+pub use crate::fmt::*;
+pub use crate::parse::ParseSizeError;
+
+/// Bytes in a kilobyte, the base-10 "kilo" prefix (1000 bytes).
+pub const KILOBYTE: i64 = 1_000;
+/// Bytes in a megabyte, the base-10 "mega" prefix (1000 kilobytes).
+pub const MEGABYTE: i64 = KILOBYTE * 1_000;
+/// Bytes in a gigabyte, the base-10 "giga" prefix (1000 megabytes).
+pub const GIGABYTE: i64 = MEGABYTE * 1_000;
+/// Bytes in a terabyte, the base-10 "tera" prefix (1000 gigabytes).
+pub const TERABYTE: i64 = GIGABYTE * 1_000;
+/// Bytes in a petabyte, the base-10 "peta" prefix (1000 terabytes).
+pub const PETABYTE: i64 = TERABYTE * 1_000;
+/// Bytes in an exabyte, the base-10 "exa" prefix (1000 petabytes).
+pub const EXABYTE: i64 = PETABYTE * 1_000;
+
+/// Bytes in a kibibyte, the base-2 "kibi" prefix (1024 bytes).
+pub const KIBIBYTE: i64 = 1_024;
+/// Bytes in a mebibyte, the base-2 "mebi" prefix (1024 kibibytes).
+pub const MEBIBYTE: i64 = KIBIBYTE * 1_024;
+/// Bytes in a gibibyte, the base-2 "gibi" prefix (1024 mebibytes).
+pub const GIBIBYTE: i64 = MEBIBYTE * 1_024;
+/// Bytes in a tebibyte, the base-2 "tebi" prefix (1024 gibibytes).
+pub const TEBIBYTE: i64 = GIBIBYTE * 1_024;
+/// Bytes in a pebibyte, the base-2 "pebi" prefix (1024 tebibytes).
+pub const PEBIBYTE: i64 = TEBIBYTE * 1_024;
+/// Bytes in an exbibyte, the base-2 "exbi" prefix (1024 pebibytes).
+pub const EXBIBYTE: i64 = PEBIBYTE * 1_024;
+
+const DEFAULT_BASE: Base = Base::Base2;
+const DEFAULT_STYLE: Style = Style::Default;
+
+/// A size in bytes, constructible from any of the common units and formattable as human-readable
+/// text via [`Size::format()`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub enum Size {
+    /// A size expressed as a raw byte count.
+    Bytes(i64),
+}
+
+impl Size {
+    /// Returns the canonical byte count represented by this `Size`.
+    pub fn bytes(&self) -> i64 {
+        match self {
+            Size::Bytes(bytes) => *bytes,
+        }
+    }
+}
+
+#[allow(non_snake_case)]
+impl Size {
+    /// Constructs a `Size` equal to `n` kilobytes (1000 bytes each).
+    pub fn KB<T: Into<i128>>(n: T) -> Self {
+        Size::Bytes((n.into() * KILOBYTE as i128) as i64)
+    }
+
+    /// Constructs a `Size` equal to `n` megabytes (1000 kilobytes each).
+    pub fn MB<T: Into<i128>>(n: T) -> Self {
+        Size::Bytes((n.into() * MEGABYTE as i128) as i64)
+    }
+
+    /// Constructs a `Size` equal to `n` gigabytes (1000 megabytes each).
+    pub fn GB<T: Into<i128>>(n: T) -> Self {
+        Size::Bytes((n.into() * GIGABYTE as i128) as i64)
+    }
+
+    /// Constructs a `Size` equal to `n` terabytes (1000 gigabytes each).
+    pub fn TB<T: Into<i128>>(n: T) -> Self {
+        Size::Bytes((n.into() * TERABYTE as i128) as i64)
+    }
+
+    /// Constructs a `Size` equal to `n` petabytes (1000 terabytes each).
+    pub fn PB<T: Into<i128>>(n: T) -> Self {
+        Size::Bytes((n.into() * PETABYTE as i128) as i64)
+    }
+
+    /// Constructs a `Size` equal to `n` exabytes (1000 petabytes each).
+    pub fn EB<T: Into<i128>>(n: T) -> Self {
+        Size::Bytes((n.into() * EXABYTE as i128) as i64)
+    }
+
+    /// Constructs a `Size` equal to `n` kibibytes (1024 bytes each).
+    pub fn KiB<T: Into<i128>>(n: T) -> Self {
+        Size::Bytes((n.into() * KIBIBYTE as i128) as i64)
+    }
+
+    /// Constructs a `Size` equal to `n` mebibytes (1024 kibibytes each).
+    pub fn MiB<T: Into<i128>>(n: T) -> Self {
+        Size::Bytes((n.into() * MEBIBYTE as i128) as i64)
+    }
+
+    /// Constructs a `Size` equal to `n` gibibytes (1024 mebibytes each).
+    pub fn GiB<T: Into<i128>>(n: T) -> Self {
+        Size::Bytes((n.into() * GIBIBYTE as i128) as i64)
+    }
+
+    /// Constructs a `Size` equal to `n` tebibytes (1024 gibibytes each).
+    pub fn TiB<T: Into<i128>>(n: T) -> Self {
+        Size::Bytes((n.into() * TEBIBYTE as i128) as i64)
+    }
+
+    /// Constructs a `Size` equal to `n` pebibytes (1024 tebibytes each).
+    pub fn PiB<T: Into<i128>>(n: T) -> Self {
+        Size::Bytes((n.into() * PEBIBYTE as i128) as i64)
+    }
+
+    /// Constructs a `Size` equal to `n` exbibytes (1024 pebibytes each).
+    pub fn EiB<T: Into<i128>>(n: T) -> Self {
+        Size::Bytes((n.into() * EXBIBYTE as i128) as i64)
+    }
+}
+
+impl Add for Size {
+    type Output = Size;
+
+    fn add(self, other: Size) -> Size {
+        Size::Bytes(self.bytes() + other.bytes())
+    }
+}
+
+impl Sub for Size {
+    type Output = Size;
+
+    fn sub(self, other: Size) -> Size {
+        Size::Bytes(self.bytes() - other.bytes())
+    }
+}
@@ -0,0 +1,138 @@
+//! The `parse` module contains [`core::str::FromStr`] support for reconstructing a [`Size`] from
+//! a human-readable textual description, the inverse of [`crate::fmt`].
+
+use core::fmt;
+use core::str::FromStr;
+use super::*;
+
+/// An error returned when a textual size description could not be parsed into a [`Size`] via
+/// [`FromStr`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum ParseSizeError {
+    /// The leading numeric portion of the string could not be parsed as a number.
+    InvalidNumber,
+    /// The trailing unit portion of the string was not one of the recognized unit spellings.
+    InvalidUnit,
+}
+
+impl fmt::Display for ParseSizeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseSizeError::InvalidNumber => write!(f, "could not parse a number from the size string"),
+            ParseSizeError::InvalidUnit => write!(f, "unrecognized unit in the size string"),
+        }
+    }
+}
+
+impl std::error::Error for ParseSizeError {}
+
+impl FromStr for Size {
+    type Err = ParseSizeError;
+
+    /// Parses a human-readable size description, e.g. `"1.5 GiB"`, `"300 kb"`, or `"1024"`, into
+    /// a [`Size`].
+    ///
+    /// A bare integer is interpreted as a raw byte count. Otherwise, the string is split into a
+    /// leading number and a trailing unit suffix; the suffix is matched case-insensitively
+    /// against both the abbreviated (`"kb"`, `"kib"`, ...) and full (`"kilobyte"`,
+    /// `"kibibyte"`, ...) spellings used by [`Style`]. A suffix containing `i` (e.g. `"kib"`,
+    /// `"mib"`) selects the base-2 factor, while a plain `"kb"`/`"mb"`/... selects the base-10
+    /// factor.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        if let Ok(bytes) = s.parse::<i64>() {
+            return Ok(Size::Bytes(bytes));
+        }
+
+        let split_at = s
+            .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == '-' || c == '+'))
+            .unwrap_or(s.len());
+        let (number, suffix) = s.split_at(split_at);
+
+        let value: f64 = number.trim().parse().map_err(|_| ParseSizeError::InvalidNumber)?;
+        let suffix = suffix.trim().to_lowercase();
+
+        let factor = match suffix.as_str() {
+            "" | "b" | "byte" | "bytes" => 1.0,
+
+            "kb" | "kilobyte" | "kilobytes" => KILOBYTE as f64,
+            "mb" | "megabyte" | "megabytes" => MEGABYTE as f64,
+            "gb" | "gigabyte" | "gigabytes" => GIGABYTE as f64,
+            "tb" | "terabyte" | "terabytes" => TERABYTE as f64,
+            "pb" | "petabyte" | "petabytes" => PETABYTE as f64,
+            "eb" | "exabyte" | "exabytes" => EXABYTE as f64,
+
+            "kib" | "kibibyte" | "kibibytes" => KIBIBYTE as f64,
+            "mib" | "mebibyte" | "mebibytes" => MEBIBYTE as f64,
+            "gib" | "gibibyte" | "gibibytes" => GIBIBYTE as f64,
+            "tib" | "tebibyte" | "tebibytes" => TEBIBYTE as f64,
+            "pib" | "pebibyte" | "pebibytes" => PEBIBYTE as f64,
+            "eib" | "exbibyte" | "exbibytes" => EXBIBYTE as f64,
+
+            _ => return Err(ParseSizeError::InvalidUnit),
+        };
+
+        Ok(Size::Bytes((value * factor) as i64))
+    }
+}
+
+impl Size {
+    /// Parses a human-readable size description (e.g. `"1.5 GiB"`, `"300 kb"`, `"1024"`) into a
+    /// [`Size`]. This is a convenience wrapper around the [`FromStr`] implementation.
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &str) -> Result<Self, ParseSizeError> {
+        <Self as FromStr>::from_str(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_bare_integers_as_bytes() {
+        assert_eq!("1024".parse::<Size>().unwrap().bytes(), 1024);
+        assert_eq!("-12".parse::<Size>().unwrap().bytes(), -12);
+    }
+
+    #[test]
+    fn parses_base10_abbreviations() {
+        assert_eq!("300 kb".parse::<Size>().unwrap().bytes(), 300 * KILOBYTE);
+        assert_eq!("1.5GB".parse::<Size>().unwrap().bytes(), (1.5 * GIGABYTE as f64) as i64);
+    }
+
+    #[test]
+    fn parses_base2_abbreviations() {
+        assert_eq!("1.5 GiB".parse::<Size>().unwrap().bytes(), (1.5 * GIBIBYTE as f64) as i64);
+        assert_eq!("2 KiB".parse::<Size>().unwrap().bytes(), 2 * KIBIBYTE);
+    }
+
+    #[test]
+    fn parses_full_unit_names_case_insensitively() {
+        assert_eq!("3 Kilobytes".parse::<Size>().unwrap().bytes(), 3 * KILOBYTE);
+        assert_eq!("1 MEBIBYTE".parse::<Size>().unwrap().bytes(), MEBIBYTE);
+    }
+
+    #[test]
+    fn round_trips_through_display_and_from_str() {
+        let original = Size::GiB(4);
+        let formatted = original.to_string();
+        assert_eq!(formatted.parse::<Size>().unwrap().bytes(), original.bytes());
+    }
+
+    #[test]
+    fn rejects_unrecognized_unit() {
+        assert_eq!("5 frobs".parse::<Size>(), Err(ParseSizeError::InvalidUnit));
+    }
+
+    #[test]
+    fn rejects_bare_suffix_with_no_number() {
+        assert_eq!("kb".parse::<Size>(), Err(ParseSizeError::InvalidNumber));
+    }
+
+    #[test]
+    fn rejects_unparseable_number() {
+        assert_eq!("??? kb".parse::<Size>(), Err(ParseSizeError::InvalidNumber));
+    }
+}
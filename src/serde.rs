@@ -0,0 +1,115 @@
+//! Optional [`serde`](https://docs.rs/serde) support for [`Size`], enabled via the `serde`
+//! feature. A `Size` always serializes to its canonical byte count, but deserializes from either
+//! a number (interpreted as bytes) or a human-readable string like `"1.5 GiB"`, so config structs
+//! can accept either representation.
+
+use core::convert::TryFrom;
+use core::fmt;
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use crate::Size;
+
+impl Serialize for Size {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_i64(self.bytes())
+    }
+}
+
+impl<'de> Deserialize<'de> for Size {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(SizeVisitor)
+    }
+}
+
+/// Visits either a number (bytes) or a human-readable string (parsed via [`FromStr`](core::str::FromStr))
+/// when deserializing a [`Size`].
+struct SizeVisitor;
+
+impl<'de> Visitor<'de> for SizeVisitor {
+    type Value = Size;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a byte count, or a human-readable size string like \"1.5 GiB\"")
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(Size::Bytes(v))
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        i64::try_from(v).map(Size::Bytes).map_err(de::Error::custom)
+    }
+
+    fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        if !v.is_finite() || v < i64::min_value() as f64 || v > i64::max_value() as f64 {
+            return Err(de::Error::custom(format!("byte count {} is out of range for an i64", v)));
+        }
+
+        Ok(Size::Bytes(v as i64))
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        v.parse::<Size>().map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serializes_to_the_canonical_byte_count() {
+        let size = Size::GiB(2);
+        assert_eq!(serde_json::to_string(&size).unwrap(), size.bytes().to_string());
+    }
+
+    #[test]
+    fn deserializes_from_a_number() {
+        let size: Size = serde_json::from_str("1610612736").unwrap();
+        assert_eq!(size.bytes(), 1_610_612_736);
+    }
+
+    #[test]
+    fn deserializes_from_a_human_readable_string() {
+        let size: Size = serde_json::from_str("\"1.5 GiB\"").unwrap();
+        assert_eq!(size.bytes(), Size::GiB(1).bytes() + Size::MiB(512).bytes());
+    }
+
+    #[test]
+    fn round_trips_through_serialize_and_deserialize() {
+        let original = Size::MiB(42);
+        let json = serde_json::to_string(&original).unwrap();
+        let restored: Size = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.bytes(), original.bytes());
+    }
+
+    #[test]
+    fn rejects_a_byte_count_that_overflows_i64() {
+        let result: Result<Size, _> = serde_json::from_str(&u64::max_value().to_string());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn rejects_a_float_byte_count_that_overflows_i64() {
+        let result: Result<Size, _> = serde_json::from_str("1e20");
+        assert!(result.is_err());
+    }
+}